@@ -0,0 +1,74 @@
+/// One hypervisor backend the extension can spawn `virtualization_mcp` for,
+/// keyed by the context server id declared in `extension.toml`.
+pub struct Backend {
+    pub id: &'static str,
+    /// `--backend <flag>` passed to the server when it supports more than
+    /// one hypervisor implementation; `None` for the default, flag-less
+    /// install.
+    pub flag: Option<&'static str>,
+}
+
+/// Known backends. Add a row here and a matching `[context_servers.<id>]`
+/// entry in `extension.toml` to expose another hypervisor.
+pub const BACKENDS: &[Backend] = &[
+    Backend { id: "virtualization-mcp", flag: None },
+    Backend { id: "virtualization-mcp-libvirt", flag: Some("libvirt") },
+    Backend { id: "virtualization-mcp-hyperv", flag: Some("hyperv") },
+    Backend { id: "virtualization-mcp-docker", flag: Some("docker") },
+    Backend { id: "virtualization-mcp-virtualbox", flag: Some("virtualbox") },
+];
+
+/// Looks up a backend by context server id, returning an error that lists
+/// every known id when `id` doesn't match one.
+pub fn find(id: &str) -> Result<&'static Backend, String> {
+    BACKENDS.iter().find(|backend| backend.id == id).ok_or_else(|| {
+        let known: Vec<&str> = BACKENDS.iter().map(|backend| backend.id).collect();
+        format!("Unknown server: {id}. Known servers: {}", known.join(", "))
+    })
+}
+
+impl Backend {
+    /// Builds the `[--backend <flag>]` arguments to pass to the resolved
+    /// server binary for this backend.
+    pub fn args(&self) -> Vec<String> {
+        match self.flag {
+            Some(flag) => vec!["--backend".to_string(), flag.to_string()],
+            None => Vec::new(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn find_known_backend() {
+        let backend = find("virtualization-mcp-libvirt").unwrap();
+        assert_eq!(backend.flag, Some("libvirt"));
+    }
+
+    #[test]
+    fn find_unknown_backend_lists_known_ids() {
+        let err = match find("virtualization-mcp-qemu") {
+            Ok(_) => panic!("expected an error for an unknown backend id"),
+            Err(err) => err,
+        };
+        assert!(err.contains("virtualization-mcp-qemu"));
+        for backend in BACKENDS {
+            assert!(err.contains(backend.id), "error should mention {}", backend.id);
+        }
+    }
+
+    #[test]
+    fn default_backend_has_no_flag_args() {
+        let backend = find("virtualization-mcp").unwrap();
+        assert!(backend.args().is_empty());
+    }
+
+    #[test]
+    fn flagged_backend_builds_backend_args() {
+        let backend = find("virtualization-mcp-docker").unwrap();
+        assert_eq!(backend.args(), vec!["--backend".to_string(), "docker".to_string()]);
+    }
+}
@@ -0,0 +1,66 @@
+use std::collections::HashMap;
+
+use zed_extension_api as zed;
+
+const ENV_FILE: &str = ".env.toml";
+
+/// Reads `.env.toml` from a worktree root, if present, and returns its
+/// string, integer and float entries as environment variables; any other
+/// value (boolean, datetime, array, table) is an error.
+///
+/// `zed::Project` (what `context_server_command` receives) only exposes
+/// worktree ids, not file contents, so this only works where a
+/// `zed::Worktree` is actually available (e.g. slash commands).
+pub fn load_from_worktree(worktree: &zed::Worktree) -> zed::Result<HashMap<String, String>> {
+    match worktree.read_text_file(ENV_FILE) {
+        Ok(contents) => parse(&contents),
+        Err(_) => Ok(HashMap::new()),
+    }
+}
+
+fn parse(contents: &str) -> zed::Result<HashMap<String, String>> {
+    let table = contents.parse::<toml::Table>().map_err(|err| format!("failed to parse {ENV_FILE}: {err}"))?;
+
+    let mut env = HashMap::with_capacity(table.len());
+    for (key, value) in table {
+        let value = match value {
+            toml::Value::String(value) => value,
+            toml::Value::Integer(value) => value.to_string(),
+            toml::Value::Float(value) => value.to_string(),
+            other => return Err(format!("{ENV_FILE} key `{key}` must be a string, integer, or float, got {other}")),
+        };
+        env.insert(key, value);
+    }
+
+    Ok(env)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_string_values() {
+        let env = parse("API_TOKEN = \"secret\"").unwrap();
+        assert_eq!(env.get("API_TOKEN"), Some(&"secret".to_string()));
+    }
+
+    #[test]
+    fn stringifies_integers_and_floats() {
+        let env = parse("PORT = 8080\nTIMEOUT = 1.5").unwrap();
+        assert_eq!(env.get("PORT"), Some(&"8080".to_string()));
+        assert_eq!(env.get("TIMEOUT"), Some(&"1.5".to_string()));
+    }
+
+    #[test]
+    fn rejects_table_values() {
+        let err = parse("[nested]\nkey = \"value\"").unwrap_err();
+        assert!(err.contains("nested"));
+    }
+
+    #[test]
+    fn rejects_invalid_toml() {
+        let err = parse("not valid toml = = =").unwrap_err();
+        assert!(err.contains("failed to parse"));
+    }
+}
@@ -1,21 +1,70 @@
-use zed_extension_api as zed;
+use std::collections::HashMap;
+
+use zed_extension_api::{self as zed, settings::ContextServerSettings};
+
+mod backends;
+mod env_config;
+mod server_binary;
+mod slash_commands;
 
 struct VirtualizationManagementExtension;
 
+impl VirtualizationManagementExtension {
+    /// Resolves the command to spawn for `backend`: a user-provided path
+    /// from Zed settings if one is set, otherwise the downloaded and
+    /// version-checked `virtualization_mcp` server binary.
+    fn resolve_command(&self, backend: &backends::Backend, command: &Option<zed::settings::CommandSettings>) -> zed::Result<(String, Vec<String>)> {
+        if let Some(command) = command {
+            if let Some(path) = command.path.clone().filter(|path| !path.is_empty()) {
+                let args = command.arguments.clone().filter(|args| !args.is_empty()).unwrap_or_else(|| backend.args());
+                return Ok((path, args));
+            }
+        }
+
+        let binary = server_binary::resolve()?;
+        Ok((binary.path, backend.args()))
+    }
+}
+
 impl zed::Extension for VirtualizationManagementExtension {
+    fn new() -> Self {
+        VirtualizationManagementExtension
+    }
+
     fn context_server_command(
         &mut self,
         id: &zed::ContextServerId,
-        _project: &zed::Project,
+        project: &zed::Project,
     ) -> zed::Result<zed::Command> {
-        match id.0.as_str() {
-            "virtualization-mcp" => Ok(zed::Command {
-                command: "uv".to_string(),
-                args: vec!["run".to_string(), "virtualization_mcp.all_tools_server:main".to_string()],
-                env: Default::default(),
-            }),
-            _ => Err(format!("Unknown server: {}", id.0)),
-        }
+        let backend = backends::find(id.as_ref())?;
+        let settings = ContextServerSettings::for_project(backend.id, project)?;
+
+        let (command, args) = self.resolve_command(backend, &settings.command)?;
+
+        let env: HashMap<String, String> = settings.command.and_then(|command| command.env).unwrap_or_default();
+
+        Ok(zed::Command {
+            command,
+            args,
+            env: env.into_iter().collect(),
+        })
+    }
+
+    fn complete_slash_command_argument(
+        &self,
+        command: zed::SlashCommand,
+        args: Vec<String>,
+    ) -> zed::Result<Vec<zed::SlashCommandArgumentCompletion>> {
+        slash_commands::complete_argument(&command, args)
+    }
+
+    fn run_slash_command(
+        &self,
+        command: zed::SlashCommand,
+        args: Vec<String>,
+        worktree: Option<&zed::Worktree>,
+    ) -> zed::Result<zed::SlashCommandOutput> {
+        slash_commands::run(&command, args, worktree)
     }
 }
 
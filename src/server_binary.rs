@@ -0,0 +1,92 @@
+use std::fs;
+
+use zed_extension_api::{self as zed, DownloadedFileType, GithubReleaseOptions};
+
+const GITHUB_REPO: &str = "sandraschi/virtualization-mcp";
+
+/// Protocol version this extension was built against. Release tarballs
+/// ship a `PROTOCOL_VERSION` file alongside the binary; if it doesn't
+/// match, we refuse to launch rather than risk talking past each other.
+const EXPECTED_PROTOCOL_VERSION: &str = "1";
+
+/// A resolved, ready-to-run server binary.
+pub struct ServerBinary {
+    pub path: String,
+}
+
+/// Resolves the `virtualization_mcp` server binary: download (and cache)
+/// the latest GitHub release for the current OS/arch if its binary isn't
+/// already in the work directory, the same way Zed's language-server
+/// extensions resolve their binaries. The release version is not pinned;
+/// the protocol version check runs on every call, cached copy or not.
+pub fn resolve() -> zed::Result<ServerBinary> {
+    let release = zed::latest_github_release(
+        GITHUB_REPO,
+        GithubReleaseOptions {
+            require_assets: true,
+            pre_release: false,
+        },
+    )?;
+
+    let (platform, arch) = zed::current_platform();
+    let asset_name = asset_name(platform, arch);
+    let asset = release
+        .assets
+        .iter()
+        .find(|asset| asset.name == asset_name)
+        .ok_or_else(|| format!("no release asset named `{asset_name}` found in {GITHUB_REPO}@{}", release.version))?;
+
+    let version_dir = format!("virtualization-mcp-server-{}", release.version);
+    let binary_path = format!("{version_dir}/virtualization_mcp_server{}", binary_suffix(platform));
+
+    if fs::metadata(&binary_path).is_err() {
+        zed::download_file(&asset.download_url, &version_dir, DownloadedFileType::GzipTar)?;
+        zed::make_file_executable(&binary_path)?;
+    }
+
+    check_protocol_version(&version_dir, &release.version)?;
+
+    Ok(ServerBinary { path: binary_path })
+}
+
+fn binary_suffix(platform: zed::Os) -> &'static str {
+    match platform {
+        zed::Os::Windows => ".exe",
+        zed::Os::Mac | zed::Os::Linux => "",
+    }
+}
+
+fn asset_name(platform: zed::Os, arch: zed::Architecture) -> String {
+    let os = match platform {
+        zed::Os::Mac => "macos",
+        zed::Os::Linux => "linux",
+        zed::Os::Windows => "windows",
+    };
+    let arch = match arch {
+        zed::Architecture::Aarch64 => "aarch64",
+        zed::Architecture::X86 => "x86",
+        zed::Architecture::X8664 => "x86_64",
+    };
+    format!("virtualization-mcp-server-{os}-{arch}.tar.gz")
+}
+
+/// Reads the `PROTOCOL_VERSION` file the release tarball ships next to the
+/// binary and errors out with an actionable message on a mismatch, instead
+/// of silently spawning a protocol-incompatible process.
+fn check_protocol_version(version_dir: &str, server_version: &str) -> zed::Result<()> {
+    let version_file = format!("{version_dir}/PROTOCOL_VERSION");
+    let reported = fs::read_to_string(&version_file)
+        .map_err(|err| format!("failed to read {version_file}: {err}"))?
+        .trim()
+        .to_string();
+
+    if reported != EXPECTED_PROTOCOL_VERSION {
+        return Err(format!(
+            "virtualization_mcp server release v{server_version} reports protocol version `{reported}`, \
+             but this extension requires `{EXPECTED_PROTOCOL_VERSION}`. \
+             Upgrade the extension, or install a matching virtualization_mcp release and point `command.path` at it in your Zed settings."
+        ));
+    }
+
+    Ok(())
+}
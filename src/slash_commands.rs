@@ -0,0 +1,103 @@
+use zed_extension_api::{self as zed, Command, SlashCommand, SlashCommandArgumentCompletion, SlashCommandOutput, SlashCommandOutputSection, Worktree};
+
+use crate::backends;
+use crate::env_config;
+use crate::server_binary;
+
+pub const VM_LIST: &str = "vm-list";
+pub const VM_START: &str = "vm-start";
+pub const VM_STOP: &str = "vm-stop";
+pub const VM_SNAPSHOT: &str = "vm-snapshot";
+
+/// Backend these commands always target: the default
+/// `context_servers.virtualization-mcp` entry. The other backends are not
+/// reachable from slash commands.
+const DEFAULT_BACKEND_ID: &str = "virtualization-mcp";
+
+fn text_output(text: String, label: String) -> SlashCommandOutput {
+    let range = 0..text.len();
+    SlashCommandOutput {
+        text,
+        sections: vec![SlashCommandOutputSection { range: range.into(), label }],
+    }
+}
+
+/// Builds the `<server binary> [--backend <flag>] <subcommand_args>`
+/// invocation for the default backend, with `.env.toml` layered in from
+/// `worktree` when one is available.
+fn backend_command(worktree: Option<&Worktree>, subcommand_args: &[&str]) -> zed::Result<Command> {
+    let backend = backends::find(DEFAULT_BACKEND_ID)?;
+    let binary = server_binary::resolve()?;
+
+    let mut args = backend.args();
+    args.extend(subcommand_args.iter().map(|arg| arg.to_string()));
+
+    let env = worktree.and_then(|worktree| env_config::load_from_worktree(worktree).ok()).unwrap_or_default();
+
+    Ok(Command {
+        command: binary.path,
+        args,
+        env: env.into_iter().collect(),
+    })
+}
+
+/// Runs `subcommand_args` against the resolved server binary via Zed's
+/// `run_command` host call (extensions can't spawn processes themselves)
+/// and returns its stdout.
+fn run_backend(worktree: Option<&Worktree>, subcommand_args: &[&str]) -> zed::Result<String> {
+    let mut command = backend_command(worktree, subcommand_args)?;
+    let output = command.output()?;
+
+    if output.status != Some(0) {
+        return Err(String::from_utf8_lossy(&output.stderr).into_owned());
+    }
+
+    Ok(String::from_utf8_lossy(&output.stdout).into_owned())
+}
+
+pub fn complete_argument(command: &SlashCommand, args: Vec<String>) -> zed::Result<Vec<SlashCommandArgumentCompletion>> {
+    match command.name.as_str() {
+        VM_START => {
+            let prefix = args.last().map(String::as_str).unwrap_or("");
+            let output = run_backend(None, &["vm", "list", "--names-only"])?;
+            let completions = output
+                .lines()
+                .map(str::trim)
+                .filter(|name| !name.is_empty() && name.starts_with(prefix))
+                .map(|name| SlashCommandArgumentCompletion {
+                    label: name.to_string(),
+                    new_text: name.to_string(),
+                    run_command: true,
+                })
+                .collect();
+            Ok(completions)
+        }
+        _ => Ok(Vec::new()),
+    }
+}
+
+pub fn run(command: &SlashCommand, args: Vec<String>, worktree: Option<&Worktree>) -> zed::Result<SlashCommandOutput> {
+    match command.name.as_str() {
+        VM_LIST => {
+            let output = run_backend(worktree, &["vm", "list"])?;
+            let count = output.lines().filter(|line| !line.trim().is_empty()).count();
+            Ok(text_output(output, format!("{count} VMs")))
+        }
+        VM_START => {
+            let name = args.first().ok_or("usage: /vm-start <vm-name>")?;
+            let output = run_backend(worktree, &["vm", "start", name])?;
+            Ok(text_output(output, format!("Started {name}")))
+        }
+        VM_STOP => {
+            let name = args.first().ok_or("usage: /vm-stop <vm-name>")?;
+            let output = run_backend(worktree, &["vm", "stop", name])?;
+            Ok(text_output(output, format!("Stopped {name}")))
+        }
+        VM_SNAPSHOT => {
+            let name = args.first().ok_or("usage: /vm-snapshot <vm-name>")?;
+            let output = run_backend(worktree, &["vm", "snapshot", name])?;
+            Ok(text_output(output, format!("Snapshot created for {name}")))
+        }
+        other => Err(format!("Unknown slash command: /{other}")),
+    }
+}